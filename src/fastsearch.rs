@@ -0,0 +1,142 @@
+use anyhow::{Context, Result};
+use curve25519_dalek::constants::{ED25519_BASEPOINT_POINT, ED25519_BASEPOINT_TABLE};
+use curve25519_dalek::edwards::EdwardsPoint;
+use curve25519_dalek::scalar::Scalar;
+use ed25519_dalek::{ExpandedSecretKey, PublicKey, Signature};
+
+/// Applies the standard ed25519 clamping to a scalar's byte representation:
+/// clear the low 3 bits (cofactor), clear the top bit, set the second-top
+/// bit. Only the freshly sampled starting scalar goes through this;
+/// scalars reached by `advance` are not re-clamped, since clamping would
+/// change their value and break the link to the walked public point.
+fn clamp(mut bytes: [u8; 32]) -> [u8; 32] {
+    bytes[0] &= 248;
+    bytes[31] &= 63;
+    bytes[31] |= 64;
+    bytes
+}
+
+/// One step of the point-increment search: a secret scalar (clamped when
+/// sampled, then incremented by one per step), the
+/// public point it corresponds to (`P = s * B`), and an independent nonce
+/// prefix used only for signing.
+///
+/// Normal ed25519 keys derive both the signing scalar and the nonce prefix
+/// by hashing a single 32-byte seed (`SHA-512(seed) = clamp(scalar) ||
+/// nonce`). That derivation is exactly what makes `s` unrecoverable from a
+/// target point, so it's incompatible with this search: we pick `s`
+/// directly and walk it forward by addition, with no seed to hash. The
+/// nonce prefix is therefore sampled independently instead, and signing
+/// goes through `ExpandedSecretKey::from_bytes`, which accepts a raw
+/// `(scalar, nonce)` pair without re-hashing it.
+pub struct FastCandidate {
+    scalar: Scalar,
+    point: EdwardsPoint,
+    nonce_prefix: [u8; 32],
+}
+
+impl FastCandidate {
+    /// Builds the starting candidate from caller-supplied random bytes:
+    /// `seed` is clamped into the secret scalar and `nonce_prefix` is kept
+    /// as is. Computes the scalar's public point via one scalar
+    /// multiplication — the
+    /// expensive step this search mode only pays once per worker, not once
+    /// per candidate.
+    pub fn sample(seed: [u8; 32], nonce_prefix: [u8; 32]) -> Self {
+        let scalar = Scalar::from_bits(clamp(seed));
+        let point = &scalar * &ED25519_BASEPOINT_TABLE;
+        Self { scalar, point, nonce_prefix }
+    }
+
+    /// Base58-encoded address for the current candidate's public point.
+    pub fn address(&self) -> String {
+        bs58::encode(self.point.compress().to_bytes()).into_string()
+    }
+
+    /// Advances to the next candidate via one point addition instead of a
+    /// fresh scalar multiplication: `P_{i+1} = P_i + B`, `s_{i+1} = s_i + 1`.
+    /// The nonce prefix is unrelated to the scalar, so it carries over
+    /// unchanged.
+    pub fn advance(&mut self) {
+        self.scalar += Scalar::one();
+        self.point += ED25519_BASEPOINT_POINT;
+    }
+
+    /// The 64-byte "expanded secret key" export for this candidate:
+    /// `scalar || nonce_prefix`, where `scalar` is the clamped starting
+    /// scalar plus the number of `advance` steps, reduced mod l once any
+    /// step has been taken. This is deliberately NOT an
+    /// ed25519-dalek seed — reloading it through `solana_sdk::Keypair` or
+    /// `ed25519_dalek::Keypair::from_bytes` would re-hash the first 32
+    /// bytes and silently produce signatures for a different, wrong key.
+    /// Reconstruct signing capability from these bytes via
+    /// `ExpandedSecretKey::from_bytes` (see `sign_message`), not `Keypair`.
+    pub fn to_bytes(&self) -> [u8; 64] {
+        let mut bytes = [0u8; 64];
+        bytes[..32].copy_from_slice(&self.scalar.to_bytes());
+        bytes[32..].copy_from_slice(&self.nonce_prefix);
+        bytes
+    }
+
+    /// Signs `message` with the real scalar this candidate's address was
+    /// mined for, by constructing an `ExpandedSecretKey` directly from
+    /// `(scalar, nonce)` bytes instead of going through `SecretKey`/
+    /// `Keypair::from_bytes` (which would re-derive an unrelated scalar via
+    /// SHA-512). The signature verifies against `self.address()`.
+    pub fn sign_message(&self, message: &[u8]) -> Result<Signature> {
+        let expanded = ExpandedSecretKey::from_bytes(&self.to_bytes())
+            .context("failed to build expanded secret key for fast-path candidate")?;
+        let public = PublicKey::from_bytes(&self.point.compress().to_bytes())
+            .context("fast-path candidate's point is not a valid ed25519 public key")?;
+        Ok(expanded.sign(message, &public))
+    }
+}
+
+/// Re-derives the public point from a fast-path export's first 32 bytes (the
+/// candidate's scalar, not a seed). The bytes are used as is, not clamped
+/// again, so an export taken after any number of `advance` steps still
+/// produces the recorded public key. `integrity::derive_pub_key` calls this
+/// for rows tagged as fast-path exports.
+pub fn fast_public_key_from_bytes(bytes: &[u8; 64]) -> [u8; 32] {
+    let mut scalar_bytes = [0u8; 32];
+    scalar_bytes.copy_from_slice(&bytes[..32]);
+    let scalar = Scalar::from_bytes_mod_order(scalar_bytes);
+    (&scalar * &ED25519_BASEPOINT_TABLE).compress().to_bytes()
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use ed25519_dalek::Verifier;
+
+    #[test]
+    fn advancing_matches_a_fresh_scalar_mult() {
+        let mut candidate = FastCandidate::sample([7u8; 32], [1u8; 32]);
+        for _ in 0..5 {
+            candidate.advance();
+        }
+
+        let expected = &candidate.scalar * &ED25519_BASEPOINT_TABLE;
+        assert_eq!(candidate.point.compress(), expected.compress());
+    }
+
+    #[test]
+    fn exported_bytes_round_trip_to_the_same_public_key() {
+        let candidate = FastCandidate::sample([3u8; 32], [9u8; 32]);
+        let exported = candidate.to_bytes();
+
+        let rederived = fast_public_key_from_bytes(&exported);
+        assert_eq!(rederived, candidate.point.compress().to_bytes());
+    }
+
+    #[test]
+    fn signature_verifies_against_the_reported_public_key() {
+        let candidate = FastCandidate::sample([5u8; 32], [2u8; 32]);
+        let public = PublicKey::from_bytes(&candidate.point.compress().to_bytes()).unwrap();
+
+        let message = b"mint authority handshake";
+        let signature = candidate.sign_message(message).unwrap();
+
+        assert!(public.verify(message, &signature).is_ok());
+    }
+}
@@ -0,0 +1,234 @@
+use crate::fastsearch;
+use crate::matcher::Pattern;
+use anyhow::{Context, Result};
+use sha2::{Digest, Sha256};
+use solana_sdk::signer::{keypair::Keypair, Signer};
+use std::fs;
+use std::path::{Path, PathBuf};
+
+/// Incrementally hashes exported bytes as they're written, so `--save-local`
+/// doesn't need to re-read the whole file afterwards just to checksum it.
+pub struct ChecksumWriter {
+    hasher: Sha256,
+}
+
+impl ChecksumWriter {
+    pub fn new() -> Self {
+        Self { hasher: Sha256::new() }
+    }
+
+    pub fn update(&mut self, bytes: &[u8]) {
+        self.hasher.update(bytes);
+    }
+
+    pub fn finalize_hex(self) -> String {
+        format!("{:x}", self.hasher.finalize())
+    }
+}
+
+/// Sidecar checksum path for an exported file, e.g. `foo.txt` -> `foo.txt.sha256`.
+pub fn sidecar_path(export_path: &Path) -> PathBuf {
+    let mut path = export_path.as_os_str().to_owned();
+    path.push(".sha256");
+    PathBuf::from(path)
+}
+
+/// Marks which search mode produced an exported private key. `Verify` used
+/// to guess this from whether `Keypair::from_bytes` happened to succeed,
+/// but `ed25519_dalek::Keypair::from_bytes` never actually checks that the
+/// public half corresponds to the secret half — it only checks that the
+/// last 32 bytes decompress to *some* valid point (RUSTSEC-2022-0093) — so
+/// a `--fast` export's independent `nonce_prefix` decompresses into a
+/// plausible-looking (but wrong) keypair close to half the time. Tagging
+/// the export explicitly removes the guess entirely.
+const EXPORT_TAG_SLOW: u8 = 0;
+const EXPORT_TAG_FAST: u8 = 1;
+
+/// Base58-encodes a slow-path `Keypair`'s 64 exported bytes (`seed ||
+/// pubkey`), prefixed with the mode tag `Verify` uses to decode it.
+pub fn encode_slow_export(bytes: &[u8; 64]) -> String {
+    encode_tagged(EXPORT_TAG_SLOW, bytes)
+}
+
+/// Base58-encodes a `--fast` candidate's 64 exported bytes (`scalar ||
+/// nonce_prefix`), prefixed with the mode tag `Verify` uses to decode it.
+pub fn encode_fast_export(bytes: &[u8; 64]) -> String {
+    encode_tagged(EXPORT_TAG_FAST, bytes)
+}
+
+fn encode_tagged(tag: u8, bytes: &[u8; 64]) -> String {
+    let mut tagged = Vec::with_capacity(65);
+    tagged.push(tag);
+    tagged.extend_from_slice(bytes);
+    bs58::encode(tagged).into_string()
+}
+
+/// One row that failed to verify, and why.
+pub struct VerifyIssue {
+    pub line_no: usize,
+    pub pub_key: String,
+    pub reason: String,
+}
+
+pub struct VerifyReport {
+    pub checksum_ok: bool,
+    pub rows_checked: usize,
+    pub issues: Vec<VerifyIssue>,
+}
+
+impl VerifyReport {
+    pub fn print(&self) {
+        if self.checksum_ok {
+            println!("✅ Checksum matches sidecar file");
+        } else {
+            println!("❌ Checksum does NOT match sidecar file — the export may be truncated or corrupted");
+        }
+
+        println!("🔎 Checked {} rows", self.rows_checked);
+        if self.issues.is_empty() {
+            println!("✅ All rows verified: stored keys re-derive their recorded address and pattern");
+        } else {
+            println!("❌ {} row(s) failed verification:", self.issues.len());
+            for issue in &self.issues {
+                println!("   line {}: {} — {}", issue.line_no, issue.pub_key, issue.reason);
+            }
+        }
+    }
+
+    pub fn is_clean(&self) -> bool {
+        self.checksum_ok && self.issues.is_empty()
+    }
+}
+
+/// Recomputes the sidecar checksum, then re-derives every recorded keypair
+/// to confirm the exported backup can be trusted before funding those
+/// mint addresses.
+pub fn verify_file(path: &Path) -> Result<VerifyReport> {
+    let contents = fs::read(path).with_context(|| format!("reading {}", path.display()))?;
+
+    let mut hasher = ChecksumWriter::new();
+    hasher.update(&contents);
+    let computed = hasher.finalize_hex();
+
+    let sidecar = sidecar_path(path);
+    let expected = fs::read_to_string(&sidecar)
+        .with_context(|| format!("reading checksum sidecar {}", sidecar.display()))?;
+    let checksum_ok = computed.trim() == expected.trim();
+
+    let mut issues = Vec::new();
+    let mut rows_checked = 0;
+
+    for (i, line) in String::from_utf8_lossy(&contents).lines().enumerate() {
+        let line_no = i + 1;
+        if line.trim().is_empty() {
+            continue;
+        }
+
+        let parts: Vec<&str> = line.splitn(3, ',').collect();
+        let [pub_key, private_key, suffix_type] = parts.as_slice() else {
+            issues.push(VerifyIssue {
+                line_no,
+                pub_key: line.to_string(),
+                reason: "expected 3 comma-separated fields".to_string(),
+            });
+            continue;
+        };
+        rows_checked += 1;
+
+        if let Err(e) = verify_row(pub_key, private_key, suffix_type) {
+            issues.push(VerifyIssue {
+                line_no,
+                pub_key: pub_key.to_string(),
+                reason: e.to_string(),
+            });
+        }
+    }
+
+    Ok(VerifyReport { checksum_ok, rows_checked, issues })
+}
+
+fn verify_row(pub_key: &str, private_key: &str, suffix_type: &str) -> Result<()> {
+    let decoded = bs58::decode(private_key)
+        .into_vec()
+        .context("private key is not valid base58")?;
+    let (&tag, bytes) = decoded
+        .split_first()
+        .ok_or_else(|| anyhow::anyhow!("private key is empty"))?;
+    let bytes: [u8; 64] = bytes
+        .try_into()
+        .map_err(|_| anyhow::anyhow!("private key is not 64 bytes (after the mode tag)"))?;
+
+    let derived_pub_key = derive_pub_key(tag, &bytes)?;
+    if derived_pub_key != pub_key {
+        anyhow::bail!("derived pub_key {} does not match recorded {}", derived_pub_key, pub_key);
+    }
+
+    let pattern = Pattern::from_label(suffix_type)?;
+    if !pattern.is_match(&derived_pub_key) {
+        anyhow::bail!("address no longer satisfies recorded pattern '{}'", suffix_type);
+    }
+
+    Ok(())
+}
+
+/// Re-derives the public key from a 64-byte export, dispatching on the
+/// explicit mode tag `encode_slow_export`/`encode_fast_export` prepend
+/// rather than guessing from whether `Keypair::from_bytes` happens to
+/// succeed. That guess used to be the only way to tell the two layouts
+/// apart, but `ed25519_dalek::Keypair::from_bytes` doesn't check that the
+/// public half actually corresponds to the secret half — it only checks
+/// that the last 32 bytes decompress to *some* valid point
+/// (RUSTSEC-2022-0093) — so a `--fast` export's independent random
+/// `nonce_prefix` would pass that check, and report a wrong derived key, on
+/// roughly half of all exports.
+fn derive_pub_key(tag: u8, bytes: &[u8; 64]) -> Result<String> {
+    match tag {
+        EXPORT_TAG_SLOW => {
+            let keypair = Keypair::from_bytes(bytes).context("not a valid slow-path keypair")?;
+            Ok(keypair.pubkey().to_string())
+        }
+        EXPORT_TAG_FAST => Ok(bs58::encode(fastsearch::fast_public_key_from_bytes(bytes)).into_string()),
+        other => anyhow::bail!("unrecognized export mode tag {}", other),
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::fastsearch::FastCandidate;
+
+    #[test]
+    fn fast_export_verifies_clean_even_though_nonce_prefix_would_decompress() {
+        // A `--fast` export's `nonce_prefix` half is independent random
+        // bytes, not a derived public key — it decompresses into *some*
+        // valid (but wrong) point close to half the time, which is exactly
+        // what made the old "guess by Keypair::from_bytes success" logic
+        // misreport clean fast-mode backups as corrupted.
+        let candidate = FastCandidate::sample([11u8; 32], [22u8; 32]);
+        let pub_key = candidate.address();
+        let private_key = encode_fast_export(&candidate.to_bytes());
+        let suffix_type = format!("suffix:{}", &pub_key[pub_key.len() - 1..]);
+
+        verify_row(&pub_key, &private_key, &suffix_type)
+            .expect("a freshly minted --fast export should verify clean");
+    }
+
+    #[test]
+    fn slow_export_round_trips() {
+        let keypair = Keypair::new();
+        let pub_key = keypair.pubkey().to_string();
+        let private_key = encode_slow_export(&keypair.to_bytes());
+        let suffix_type = format!("suffix:{}", &pub_key[pub_key.len() - 1..]);
+
+        verify_row(&pub_key, &private_key, &suffix_type)
+            .expect("a freshly minted slow-path export should verify clean");
+    }
+
+    #[test]
+    fn mismatched_pub_key_is_rejected() {
+        let candidate = FastCandidate::sample([33u8; 32], [44u8; 32]);
+        let private_key = encode_fast_export(&candidate.to_bytes());
+
+        assert!(verify_row("not-the-real-pub-key", &private_key, "suffix:a").is_err());
+    }
+}
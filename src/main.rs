@@ -2,23 +2,47 @@ use anyhow::Result;
 use bs58;
 use clap::{Parser, Subcommand};
 use dotenv::dotenv;
-use reqwest::Client;
-use serde::{Deserialize, Serialize};
 use solana_sdk::signer::{keypair::Keypair, Signer};
-use std::env;
+use std::fs;
 use std::fs::OpenOptions;
-use std::io::Write;
+use std::io::Write as _;
+use std::path::Path;
 use std::sync::atomic::{AtomicBool, AtomicU64, Ordering};
 use std::sync::Arc;
+use std::sync::Mutex;
 use std::thread;
 use std::time::Instant;
-use tokio::sync::mpsc;
 use tokio::time::Duration;
 
+mod fastsearch;
+mod integrity;
+mod matcher;
+mod metrics;
+mod search;
+mod server;
+mod stats;
+mod store;
+
+use integrity::{sidecar_path, ChecksumWriter};
+use matcher::{Matcher, MultiMatcher, Pattern};
+use metrics::Metrics;
+use stats::{Benchmark, Histogram, Stats, ThroughputSeries};
+use store::{AddressInsert, Store, StoreBackend};
+
 #[derive(Parser)]
 #[command(name = "solana-mint-generator")]
 #[command(about = "Generate Solana mint addresses with specific suffixes")]
 struct Cli {
+    /// Expose Prometheus metrics on this address (e.g. 127.0.0.1:9000) while running
+    #[arg(long, global = true)]
+    metrics_addr: Option<String>,
+
+    /// Use the ed25519 point-increment search (one scalar mult per worker,
+    /// then one point addition per candidate) instead of a fresh keypair
+    /// per attempt
+    #[arg(long, global = true)]
+    fast: bool,
+
     #[command(subcommand)]
     command: Commands,
 }
@@ -61,306 +85,379 @@ enum Commands {
         #[arg(long, default_value = "false")]
         save_local: bool,
     },
+    /// Generate addresses matching arbitrary prefix/suffix/regex patterns
+    Vanity {
+        /// Address must start with one of these (repeatable)
+        #[arg(long = "prefix")]
+        prefixes: Vec<String>,
+        /// Address must end with one of these (repeatable)
+        #[arg(long = "suffix")]
+        suffixes: Vec<String>,
+        /// Address must match this regex (repeatable)
+        #[arg(long = "regex")]
+        regexes: Vec<String>,
+        /// Match prefixes/suffixes/regexes case-insensitively
+        #[arg(long, default_value = "false")]
+        ignore_case: bool,
+        /// Number of addresses to generate
+        #[arg(short, long, default_value = "1")]
+        count: u32,
+        /// Batch size for database uploads (0 = upload all at end)
+        #[arg(short, long, default_value = "10")]
+        batch_size: u32,
+        /// Save to local file as backup
+        #[arg(long, default_value = "false")]
+        save_local: bool,
+    },
+    /// Hammer keypair generation for a fixed window and report throughput/attempt distributions
+    Bench {
+        /// Suffix pattern length to simulate (e.g. 4 or 5)
+        #[arg(short = 'l', long, default_value = "4")]
+        pattern_len: usize,
+        /// How long to run the benchmark for, in seconds
+        #[arg(short, long, default_value = "30")]
+        duration_secs: u64,
+        /// Append a CSV row with these results to this file
+        #[arg(long, default_value = "bench_metrics.csv")]
+        metrics_file: String,
+    },
+    /// Run as a daemon exposing an HTTP API for submitting and polling vanity jobs
+    Serve {
+        /// Address to listen on
+        #[arg(long, default_value = "127.0.0.1:3000")]
+        addr: String,
+        /// Maximum number of jobs searching concurrently
+        #[arg(long, default_value = "4")]
+        max_concurrent_jobs: usize,
+    },
+    /// Verify a --save-local export against its checksum and re-derive each keypair
+    Verify {
+        /// Path to the exported `pub_key,private_key,suffix_type` file
+        file: String,
+    },
 }
 
-#[derive(Serialize, Deserialize)]
-struct AddressRecord {
-    pub_key: String,
-    private_key: String,
-    suffix_type: String,
-    created_at: String,
-}
-
-#[derive(Serialize, Clone)]
-struct SupabaseInsert {
-    pub_key: String,
-    private_key: String, // Base58 encoded for Solana compatibility
-    suffix_type: String,
-}
-
-struct SupabaseClient {
-    client: Client,
-    url: String,
-    key: String,
-}
-
-impl SupabaseClient {
-    fn new() -> Result<Self> {
-        let url = env::var("SUPABASE_URL")?;
-        let key = env::var("SUPABASE_ANON_KEY")?;
-        
-        Ok(Self {
-            client: Client::new(),
-            url,
-            key,
-        })
-    }
-
-    async fn insert_address(&self, record: SupabaseInsert) -> Result<()> {
-        let response = self
-            .client
-            .post(&format!("{}/rest/v1/mint_addresses", self.url))
-            .header("apikey", &self.key)
-            .header("Authorization", format!("Bearer {}", self.key))
-            .header("Content-Type", "application/json")
-            .json(&record)
-            .send()
-            .await?;
-
-        if response.status().is_success() {
-            println!("✅ Successfully saved address {} to Supabase", record.pub_key);
-        } else {
-            let error_text = response.text().await?;
-            println!("❌ Failed to save address: {}", error_text);
-        }
-
-        Ok(())
-    }
-
-    async fn insert_addresses_batch(&self, records: Vec<SupabaseInsert>) -> Result<()> {
-        if records.is_empty() {
-            return Ok(());
-        }
-
-        let response = self
-            .client
-            .post(&format!("{}/rest/v1/mint_addresses", self.url))
-            .header("apikey", &self.key)
-            .header("Authorization", format!("Bearer {}", self.key))
-            .header("Content-Type", "application/json")
-            .json(&records)
-            .send()
-            .await?;
-
-        if response.status().is_success() {
-            println!("✅ Successfully saved {} addresses to Supabase in batch", records.len());
-        } else {
-            let error_text = response.text().await?;
-            println!("❌ Failed to save batch: {}", error_text);
-            
-            // Fallback: try individual inserts
-            println!("🔄 Retrying with individual inserts...");
-            for record in records {
-                if let Err(e) = self.insert_address(record).await {
-                    println!("⚠️  Individual insert failed: {}", e);
-                }
-            }
-        }
-
-        Ok(())
-    }
-}
-
-struct AddressGenerator {
-    supabase: SupabaseClient,
+struct AddressGenerator<S: Store> {
+    store: S,
     attempts: Arc<AtomicU64>,
+    metrics: Option<Arc<Metrics>>,
 }
 
-impl AddressGenerator {
-    fn new(supabase: SupabaseClient) -> Self {
+impl<S: Store> AddressGenerator<S> {
+    fn new(store: S, metrics: Option<Arc<Metrics>>) -> Self {
         Self {
-            supabase,
+            store,
             attempts: Arc::new(AtomicU64::new(0)),
+            metrics,
         }
     }
 
-    async fn generate_addresses(&self, suffix: &str, count: u32, batch_size: u32, save_local: bool) -> Result<Vec<(Keypair, String)>> {
+    async fn generate_addresses(
+        &self,
+        matcher: Arc<dyn Matcher>,
+        description: &str,
+        count: u32,
+        batch_size: u32,
+        save_local: bool,
+        fast: bool,
+    ) -> Result<Vec<String>> {
         let mut results = Vec::new();
         let mut batch_records = Vec::new();
         let start_time = Instant::now();
-        
+
         // Get number of CPU cores
         let num_cores = thread::available_parallelism()
             .map(|p| p.get())
             .unwrap_or(1);
-        
-        println!("🔍 Generating {} addresses ending with '{}' using {} CPU cores...", count, suffix, num_cores);
+
+        println!("🔍 Generating {} addresses matching {} using {} CPU cores...", count, description, num_cores);
         println!("🚀 Running at 100% CPU utilization...");
-        
+
         if batch_size == 0 {
             println!("💾 Upload strategy: Save all addresses at the end");
         } else {
             println!("💾 Upload strategy: Batch upload every {} addresses", batch_size);
         }
-        
+
         // Prepare local file if needed
+        let local_filename = format!("vanity_addresses_{}.txt", chrono::Utc::now().format("%Y%m%d_%H%M%S"));
         let mut local_file = if save_local {
-            let filename = format!("{}_addresses_{}.txt", suffix, chrono::Utc::now().format("%Y%m%d_%H%M%S"));
-            println!("📁 Saving local backup to: {}", filename);
+            println!("📁 Saving local backup to: {}", local_filename);
             Some(OpenOptions::new()
                 .create(true)
                 .write(true)
                 .append(true)
-                .open(filename)?)
+                .open(&local_filename)?)
         } else {
             None
         };
-        
+        let mut local_checksum = ChecksumWriter::new();
+
         for i in 0..count {
-            let keypair = self.find_address_with_suffix(suffix, num_cores).await?;
-            let pub_key = keypair.pubkey().to_string();
-            
-            println!("✨ Found address {}/{}: {}", i + 1, count, pub_key);
-            
+            let found = search::find_one(
+                matcher.clone(),
+                self.attempts.clone(),
+                self.metrics.clone(),
+                num_cores,
+                fast,
+                Arc::new(AtomicBool::new(false)),
+            )
+            .await
+            .ok_or_else(|| anyhow::anyhow!("All worker threads finished without finding a matching address"))?;
+            let pub_key = found.pub_key;
+            let matched_label = found.label;
+
+            println!("✨ Found address {}/{}: {} (matched {})", i + 1, count, pub_key, matched_label);
+
             // Prepare database record
-            let record = SupabaseInsert {
+            let record = AddressInsert {
                 pub_key: pub_key.clone(),
-                private_key: bs58::encode(keypair.to_bytes()).into_string(),
-                suffix_type: suffix.to_string(),
+                private_key: found.private_key_b58.clone(),
+                suffix_type: matched_label.clone(),
             };
-            
-            // Save to local file if enabled
+
+            // Save to local file if enabled, hashing incrementally as we
+            // write instead of re-reading the whole file afterwards
             if let Some(ref mut file) = local_file {
-                writeln!(file, "{},{},{}", pub_key, bs58::encode(keypair.to_bytes()).into_string(), suffix)?;
+                let line = format!("{},{},{}\n", pub_key, found.private_key_b58, matched_label);
+                file.write_all(line.as_bytes())?;
+                local_checksum.update(line.as_bytes());
             }
-            
+
             batch_records.push(record);
-            results.push((keypair, pub_key));
-            
+            results.push(pub_key);
+
             // Handle batch uploads
             if batch_size > 0 && batch_records.len() >= batch_size as usize {
-                if let Err(e) = self.supabase.insert_addresses_batch(batch_records.clone()).await {
-                    println!("⚠️  Failed to save batch to Supabase: {}", e);
+                if let Err(e) = self.store.insert_batch(batch_records.clone()).await {
+                    println!("⚠️  Failed to save batch: {}", e);
                 }
                 batch_records.clear();
             }
         }
-        
+
         // Upload any remaining records
         if !batch_records.is_empty() {
-            if let Err(e) = self.supabase.insert_addresses_batch(batch_records).await {
-                println!("⚠️  Failed to save final batch to Supabase: {}", e);
+            if let Err(e) = self.store.insert_batch(batch_records).await {
+                println!("⚠️  Failed to save final batch: {}", e);
             }
         }
-        
+
         let elapsed = start_time.elapsed();
         let total_attempts = self.attempts.load(Ordering::Relaxed);
-        
+
         println!("\n📊 Generation complete!");
         println!("⏱️  Total time: {:?}", elapsed);
         println!("🎯 Total attempts: {}", total_attempts);
         println!("📈 Average attempts per address: {:.2}", total_attempts as f64 / count as f64);
         println!("⚡ Performance: {:.2} attempts/second", total_attempts as f64 / elapsed.as_secs_f64());
-        
+
         if save_local {
-            println!("📁 Local backup saved successfully");
+            let checksum_path = sidecar_path(Path::new(&local_filename));
+            fs::write(&checksum_path, local_checksum.finalize_hex())?;
+            println!("📁 Local backup saved successfully (checksum: {})", checksum_path.display());
         }
-        
+
         Ok(results)
     }
+}
+
+/// Picks a random base58 string of `len` characters by slicing the tail
+/// off a freshly generated address, so the benchmark doesn't need its own
+/// RNG dependency.
+fn random_base58_pattern(len: usize) -> String {
+    loop {
+        let address = Keypair::new().pubkey().to_string();
+        if address.len() >= len {
+            return address[address.len() - len..].to_string();
+        }
+    }
+}
+
+/// Benchmarks raw keypair-generation throughput against a suffix pattern
+/// of a fixed length, for a fixed wall-clock window, across all CPU cores.
+struct KeypairBenchmark {
+    pattern_len: usize,
+    num_cores: usize,
+    pattern: String,
+}
+
+impl KeypairBenchmark {
+    fn new(pattern_len: usize) -> Self {
+        let num_cores = thread::available_parallelism().map(|p| p.get()).unwrap_or(1);
+        Self {
+            pattern_len,
+            num_cores,
+            pattern: String::new(),
+        }
+    }
+}
+
+impl Benchmark for KeypairBenchmark {
+    fn prepare(&mut self) {
+        self.pattern = random_base58_pattern(self.pattern_len);
+    }
+
+    fn run(&mut self, window: Duration) -> Stats {
+        let attempts = Arc::new(AtomicU64::new(0));
+        let matches = Arc::new(AtomicU64::new(0));
+        let stop = Arc::new(AtomicBool::new(false));
+        let histogram = Arc::new(Mutex::new(Histogram::new()));
 
-    async fn find_address_with_suffix(&self, suffix: &str, num_cores: usize) -> Result<Keypair> {
-        let (tx, mut rx) = mpsc::channel::<Keypair>(1);
-        let found = Arc::new(AtomicBool::new(false));
-        let attempts = self.attempts.clone();
-        let suffix_owned = suffix.to_string();
-        
-        // Spawn worker threads on all CPU cores
         let mut handles = Vec::new();
-        for thread_id in 0..num_cores {
-            let tx = tx.clone();
-            let found = found.clone();
+        for _ in 0..self.num_cores {
             let attempts = attempts.clone();
-            let suffix = suffix_owned.clone();
-            
-            let handle = tokio::task::spawn_blocking(move || {
+            let matches = matches.clone();
+            let stop = stop.clone();
+            let histogram = histogram.clone();
+            let pattern = self.pattern.clone();
+
+            handles.push(thread::spawn(move || {
                 let mut local_attempts = 0u64;
-                let mut last_report = Instant::now();
-                
-                loop {
-                    // Check if another thread found the address
-                    if found.load(Ordering::Relaxed) {
-                        break;
-                    }
-                    
+                while !stop.load(Ordering::Relaxed) {
                     let keypair = Keypair::new();
-                    let pubkey = keypair.pubkey();
-                    let address = pubkey.to_string();
-                    
+                    let address = keypair.pubkey().to_string();
+
                     local_attempts += 1;
                     attempts.fetch_add(1, Ordering::Relaxed);
-                    
-                    // Report progress from thread 0 only every 5 seconds
-                    if thread_id == 0 && last_report.elapsed() >= Duration::from_secs(5) {
-                        let total_attempts = attempts.load(Ordering::Relaxed);
-                        println!("🔄 Total attempts: {} (searching for '{}' on {} cores)", 
-                                total_attempts, suffix, num_cores);
-                        last_report = Instant::now();
-                    }
-                    
-                    if address.ends_with(&suffix) {
-                        // Signal other threads to stop
-                        found.store(true, Ordering::Relaxed);
-                        
-                        println!("🎉 Found matching address after {} local attempts on thread {}!", 
-                                local_attempts, thread_id);
-                        
-                        // Send the result
-                        if tx.blocking_send(keypair).is_err() {
-                            // Channel was closed, another thread might have found it first
-                            break;
-                        }
-                        break;
+
+                    if address.ends_with(&pattern) {
+                        matches.fetch_add(1, Ordering::Relaxed);
+                        histogram.lock().unwrap().record(local_attempts);
+                        local_attempts = 0;
                     }
-                    
-                    // No delay - run at 100% CPU
                 }
-            });
-            
-            handles.push(handle);
+            }));
+        }
+
+        // Sample the shared attempts counter on a fixed tick to build a
+        // throughput time series for the duration of the window.
+        let mut throughput = ThroughputSeries::new();
+        let tick = Duration::from_millis(250);
+        let start = Instant::now();
+        let mut last_attempts = 0u64;
+        while start.elapsed() < window {
+            thread::sleep(tick);
+            let now = attempts.load(Ordering::Relaxed);
+            throughput.push((now - last_attempts) as f64 / tick.as_secs_f64());
+            last_attempts = now;
         }
-        
-        // Drop the original sender so the channel can close when all workers are done
-        drop(tx);
-        
-        // Wait for the first result
-        let result = rx.recv().await.ok_or_else(|| {
-            anyhow::anyhow!("All worker threads finished without finding a matching address")
-        })?;
-        
-        // Signal all threads to stop
-        found.store(true, Ordering::Relaxed);
-        
-        // Wait for all threads to complete
+
+        stop.store(true, Ordering::Relaxed);
         for handle in handles {
-            let _ = handle.await;
+            let _ = handle.join();
+        }
+
+        let histogram = Arc::try_unwrap(histogram)
+            .map(|m| m.into_inner().unwrap())
+            .unwrap_or_else(|_| Histogram::new());
+
+        Stats {
+            pattern: self.pattern.clone(),
+            pattern_len: self.pattern_len,
+            total_attempts: attempts.load(Ordering::Relaxed),
+            elapsed: start.elapsed(),
+            throughput,
+            histogram,
+            matches: matches.load(Ordering::Relaxed),
         }
-        
-        Ok(result)
     }
 }
 
+/// Builds the generator on demand, so subcommands that don't touch the
+/// database (e.g. `bench`) don't require a storage backend to be configured.
+async fn build_generator(metrics: Option<Arc<Metrics>>) -> Result<AddressGenerator<StoreBackend>> {
+    let store = StoreBackend::from_env().await?;
+    Ok(AddressGenerator::new(store, metrics))
+}
+
+/// If `--metrics-addr` was given, builds a `Metrics` instance and starts
+/// its `/metrics` HTTP listener in the background.
+fn start_metrics(metrics_addr: Option<String>) -> Result<Option<Arc<Metrics>>> {
+    let Some(addr) = metrics_addr else {
+        return Ok(None);
+    };
+    let addr: std::net::SocketAddr = addr.parse()?;
+    let metrics = Arc::new(Metrics::new()?);
+    let metrics_for_server = metrics.clone();
+    tokio::spawn(async move {
+        if let Err(e) = metrics::serve(addr, metrics_for_server).await {
+            println!("⚠️  Metrics listener stopped: {}", e);
+        }
+    });
+    Ok(Some(metrics))
+}
+
 #[tokio::main]
 async fn main() -> Result<()> {
     dotenv().ok();
-    
+
     let cli = Cli::parse();
-    
-    // Initialize Supabase client
-    let supabase = SupabaseClient::new().map_err(|e| {
-        anyhow::anyhow!(
-            "Failed to initialize Supabase client: {}. Please check your .env file.", 
-            e
-        )
-    })?;
-    
-    let generator = AddressGenerator::new(supabase);
-    
+    let metrics = start_metrics(cli.metrics_addr)?;
+    let fast = cli.fast;
+
     match cli.command {
         Commands::Pump { count, batch_size, save_local } => {
-            generator.generate_addresses("pump", count, batch_size, save_local).await?;
+            let generator = build_generator(metrics).await?;
+            let matcher: Arc<dyn Matcher> = Arc::new(MultiMatcher::single_suffix("pump")?);
+            generator.generate_addresses(matcher, "suffix:pump", count, batch_size, save_local, fast).await?;
         }
         Commands::Bonk { count, batch_size, save_local } => {
-            generator.generate_addresses("bonk", count, batch_size, save_local).await?;
+            let generator = build_generator(metrics).await?;
+            let matcher: Arc<dyn Matcher> = Arc::new(MultiMatcher::single_suffix("bonk")?);
+            generator.generate_addresses(matcher, "suffix:bonk", count, batch_size, save_local, fast).await?;
         }
         Commands::Both { count, batch_size, save_local } => {
             println!("🚀 Generating both pump and bonk addresses...\n");
-            
-            generator.generate_addresses("pump", count, batch_size, save_local).await?;
+
+            let generator = build_generator(metrics).await?;
+            let pump: Arc<dyn Matcher> = Arc::new(MultiMatcher::single_suffix("pump")?);
+            generator.generate_addresses(pump, "suffix:pump", count, batch_size, save_local, fast).await?;
             println!();
-            generator.generate_addresses("bonk", count, batch_size, save_local).await?;
+            let bonk: Arc<dyn Matcher> = Arc::new(MultiMatcher::single_suffix("bonk")?);
+            generator.generate_addresses(bonk, "suffix:bonk", count, batch_size, save_local, fast).await?;
+        }
+        Commands::Vanity { prefixes, suffixes, regexes, ignore_case, count, batch_size, save_local } => {
+            let mut patterns = Vec::new();
+            for p in &prefixes {
+                patterns.push(Pattern::prefix(p, ignore_case)?);
+            }
+            for s in &suffixes {
+                patterns.push(Pattern::suffix(s, ignore_case)?);
+            }
+            for r in &regexes {
+                patterns.push(Pattern::regex(r, ignore_case)?);
+            }
+            let matcher: Arc<dyn Matcher> = Arc::new(MultiMatcher::new(patterns)?);
+            let description = format!(
+                "{} pattern(s)",
+                prefixes.len() + suffixes.len() + regexes.len()
+            );
+            let generator = build_generator(metrics).await?;
+            generator.generate_addresses(matcher, &description, count, batch_size, save_local, fast).await?;
+        }
+        Commands::Bench { pattern_len, duration_secs, metrics_file } => {
+            let mut bench = KeypairBenchmark::new(pattern_len);
+            bench.prepare();
+            println!("🏋️  Benchmarking {}-char suffix matches for {}s...", pattern_len, duration_secs);
+            let stats = bench.run(Duration::from_secs(duration_secs));
+            stats.print_table();
+            stats.append_csv(std::path::Path::new(&metrics_file))?;
+            println!("📄 Appended results to {}", metrics_file);
+        }
+        Commands::Serve { addr, max_concurrent_jobs } => {
+            let store = StoreBackend::from_env().await?;
+            let addr: std::net::SocketAddr = addr.parse()?;
+            server::serve(addr, store, max_concurrent_jobs, fast, metrics).await?;
+        }
+        Commands::Verify { file } => {
+            let report = integrity::verify_file(Path::new(&file))?;
+            report.print();
+            if !report.is_clean() {
+                anyhow::bail!("verification failed for {}", file);
+            }
         }
     }
-    
+
     Ok(())
-} 
\ No newline at end of file
+}
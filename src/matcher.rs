@@ -0,0 +1,192 @@
+use anyhow::{bail, Result};
+use regex::{Regex, RegexBuilder};
+
+/// Characters Solana's base58 alphabet never produces (they're excluded
+/// upstream to avoid visual ambiguity between 0/O and I/l).
+const BASE58_INVALID_CHARS: [char; 4] = ['0', 'O', 'I', 'l'];
+
+/// Returns the first base58-invalid character found in `s`, if any.
+fn first_invalid_base58_char(s: &str) -> Option<char> {
+    s.chars().find(|c| BASE58_INVALID_CHARS.contains(c))
+}
+
+/// One matchable target: a literal prefix/suffix, or an arbitrary regex
+/// over the base58-encoded address.
+#[derive(Clone)]
+enum PatternKind {
+    Prefix(String),
+    Suffix(String),
+    Regex(Regex),
+}
+
+/// A single pattern plus how it should be displayed/recorded when it hits.
+#[derive(Clone)]
+pub struct Pattern {
+    label: String,
+    kind: PatternKind,
+    ignore_case: bool,
+}
+
+impl Pattern {
+    pub fn prefix(pattern: &str, ignore_case: bool) -> Result<Self> {
+        if let Some(c) = first_invalid_base58_char(pattern) {
+            bail!(
+                "prefix pattern '{}' contains '{}', which never appears in base58 addresses",
+                pattern,
+                c
+            );
+        }
+        Ok(Self {
+            label: format!("prefix:{}", pattern),
+            kind: PatternKind::Prefix(pattern.to_string()),
+            ignore_case,
+        })
+    }
+
+    pub fn suffix(pattern: &str, ignore_case: bool) -> Result<Self> {
+        if let Some(c) = first_invalid_base58_char(pattern) {
+            bail!(
+                "suffix pattern '{}' contains '{}', which never appears in base58 addresses",
+                pattern,
+                c
+            );
+        }
+        Ok(Self {
+            label: format!("suffix:{}", pattern),
+            kind: PatternKind::Suffix(pattern.to_string()),
+            ignore_case,
+        })
+    }
+
+    pub fn regex(pattern: &str, ignore_case: bool) -> Result<Self> {
+        let compiled = RegexBuilder::new(pattern)
+            .case_insensitive(ignore_case)
+            .build()
+            .map_err(|e| anyhow::anyhow!("invalid regex pattern '{}': {}", pattern, e))?;
+        Ok(Self {
+            label: format!("regex:{}", pattern),
+            kind: PatternKind::Regex(compiled),
+            ignore_case,
+        })
+    }
+
+    /// Human/record-friendly name for whichever pattern matched, used as
+    /// the `suffix_type` stored alongside a found address.
+    pub fn label(&self) -> &str {
+        &self.label
+    }
+
+    /// Reconstructs a `Pattern` from a `label()` string (e.g. `"suffix:pump"`),
+    /// so a saved `suffix_type` can be re-checked against the address it was
+    /// recorded for. Case-insensitivity isn't encoded in the label, so
+    /// round-tripped patterns are always matched case-sensitively.
+    pub fn from_label(label: &str) -> Result<Self> {
+        let (kind, value) = label
+            .split_once(':')
+            .ok_or_else(|| anyhow::anyhow!("malformed pattern label '{}'", label))?;
+        match kind {
+            "prefix" => Self::prefix(value, false),
+            "suffix" => Self::suffix(value, false),
+            "regex" => Self::regex(value, false),
+            other => bail!("unknown pattern kind '{}' in label '{}'", other, label),
+        }
+    }
+
+
+    pub(crate) fn is_match(&self, address: &str) -> bool {
+        match &self.kind {
+            PatternKind::Prefix(p) => {
+                if self.ignore_case {
+                    address.to_ascii_lowercase().starts_with(&p.to_ascii_lowercase())
+                } else {
+                    address.starts_with(p)
+                }
+            }
+            PatternKind::Suffix(s) => {
+                if self.ignore_case {
+                    address.to_ascii_lowercase().ends_with(&s.to_ascii_lowercase())
+                } else {
+                    address.ends_with(s)
+                }
+            }
+            PatternKind::Regex(re) => re.is_match(address),
+        }
+    }
+}
+
+/// Something a generated address can be checked against. Implementations
+/// must be cheap to call millions of times per second from worker threads.
+pub trait Matcher: Send + Sync {
+    /// Returns the label of the first pattern that matches `address`, if
+    /// any of them do.
+    fn matches(&self, address: &str) -> Option<&str>;
+}
+
+/// A matcher over any number of patterns — an address hits as soon as one
+/// of them does, so a single search can satisfy several targets at once.
+pub struct MultiMatcher {
+    patterns: Vec<Pattern>,
+}
+
+impl MultiMatcher {
+    pub fn new(patterns: Vec<Pattern>) -> Result<Self> {
+        if patterns.is_empty() {
+            bail!("at least one pattern is required");
+        }
+        Ok(Self { patterns })
+    }
+
+    /// Convenience constructor for the existing single-suffix searches
+    /// (`pump`/`bonk`) so they can keep using the `Matcher` worker loop.
+    pub fn single_suffix(suffix: &str) -> Result<Self> {
+        Self::new(vec![Pattern::suffix(suffix, false)?])
+    }
+}
+
+impl Matcher for MultiMatcher {
+    fn matches(&self, address: &str) -> Option<&str> {
+        self.patterns
+            .iter()
+            .find(|p| p.is_match(address))
+            .map(|p| p.label())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn rejects_base58_invalid_chars() {
+        assert!(Pattern::suffix("pump0", false).is_err());
+        assert!(Pattern::prefix("OOPS", false).is_err());
+        assert!(Pattern::suffix("pump", false).is_ok());
+    }
+
+    #[test]
+    fn multi_matcher_matches_any_pattern() {
+        let matcher = MultiMatcher::new(vec![
+            Pattern::suffix("bonk", false).unwrap(),
+            Pattern::prefix("ABC", false).unwrap(),
+        ])
+        .unwrap();
+
+        assert_eq!(matcher.matches("xyzbonk"), Some("suffix:bonk"));
+        assert_eq!(matcher.matches("ABCxyz"), Some("prefix:ABC"));
+        assert_eq!(matcher.matches("nopenope"), None);
+    }
+
+    #[test]
+    fn case_insensitive_suffix_matches() {
+        let matcher = MultiMatcher::new(vec![Pattern::suffix("PUMP", true).unwrap()]).unwrap();
+        assert_eq!(matcher.matches("xyzpump"), Some("suffix:PUMP"));
+    }
+
+    #[test]
+    fn pattern_round_trips_through_its_label() {
+        let pattern = Pattern::suffix("bonk", false).unwrap();
+        let reconstructed = Pattern::from_label(pattern.label()).unwrap();
+        assert!(reconstructed.is_match("xyzbonk"));
+        assert!(!reconstructed.is_match("xyzpump"));
+    }
+}
@@ -0,0 +1,100 @@
+use anyhow::Result;
+use axum::{extract::State, routing::get, Router};
+use prometheus::{Encoder, IntCounter, IntCounterVec, IntGauge, Opts, Registry, TextEncoder};
+use std::net::SocketAddr;
+use std::sync::Arc;
+
+/// Prometheus counters/gauges for a generation run, scraped over `/metrics`
+/// instead of read off stdout. Cheap to update: the worker loop only
+/// touches this every N iterations (see `METRICS_FLUSH_INTERVAL` in
+/// search.rs), not on every keypair.
+pub struct Metrics {
+    registry: Registry,
+    attempts_total: IntCounter,
+    matches_total: IntCounterVec,
+    attempts_per_sec: prometheus::Gauge,
+    active_workers: IntGauge,
+}
+
+impl Metrics {
+    pub fn new() -> Result<Self> {
+        let registry = Registry::new();
+
+        let attempts_total = IntCounter::new(
+            "mint_generator_attempts_total",
+            "Total keypairs generated while searching for matches",
+        )?;
+        let matches_total = IntCounterVec::new(
+            Opts::new("mint_generator_matches_total", "Matches found, labeled by pattern"),
+            &["pattern"],
+        )?;
+        let attempts_per_sec = prometheus::Gauge::new(
+            "mint_generator_attempts_per_second",
+            "Keypair generation throughput over a sliding window",
+        )?;
+        let active_workers = IntGauge::new(
+            "mint_generator_active_workers",
+            "Number of worker threads currently searching",
+        )?;
+
+        registry.register(Box::new(attempts_total.clone()))?;
+        registry.register(Box::new(matches_total.clone()))?;
+        registry.register(Box::new(attempts_per_sec.clone()))?;
+        registry.register(Box::new(active_workers.clone()))?;
+
+        Ok(Self {
+            registry,
+            attempts_total,
+            matches_total,
+            attempts_per_sec,
+            active_workers,
+        })
+    }
+
+    pub fn record_attempts(&self, n: u64) {
+        self.attempts_total.inc_by(n);
+    }
+
+    /// Buckets by pattern *kind* (`"suffix"`, `"prefix"`, `"regex"`) rather
+    /// than the full label, which for jobs submitted through `POST /jobs`
+    /// embeds an unbounded, client-supplied pattern string. Labeling by the
+    /// raw string would give every distinct pattern a client ever submits
+    /// its own permanent Prometheus time series — unbounded cardinality in
+    /// a long-lived daemon.
+    pub fn record_match(&self, pattern_label: &str) {
+        let kind = pattern_label.split(':').next().unwrap_or(pattern_label);
+        self.matches_total.with_label_values(&[kind]).inc();
+    }
+
+    pub fn set_attempts_per_sec(&self, value: f64) {
+        self.attempts_per_sec.set(value);
+    }
+
+    pub fn set_active_workers(&self, count: i64) {
+        self.active_workers.set(count);
+    }
+
+    fn encode(&self) -> Result<String> {
+        let mut buffer = Vec::new();
+        let encoder = TextEncoder::new();
+        encoder.encode(&self.registry.gather(), &mut buffer)?;
+        Ok(String::from_utf8(buffer)?)
+    }
+}
+
+async fn metrics_handler(State(metrics): State<Arc<Metrics>>) -> String {
+    metrics.encode().unwrap_or_else(|e| format!("# failed to encode metrics: {}\n", e))
+}
+
+/// Serves `/metrics` in Prometheus text format until the process exits, so
+/// long searches can be scraped into Grafana instead of read off stdout.
+pub async fn serve(addr: SocketAddr, metrics: Arc<Metrics>) -> Result<()> {
+    let app = Router::new()
+        .route("/metrics", get(metrics_handler))
+        .with_state(metrics);
+
+    println!("📡 Serving Prometheus metrics on http://{}/metrics", addr);
+    let listener = tokio::net::TcpListener::bind(addr).await?;
+    axum::serve(listener, app).await?;
+    Ok(())
+}
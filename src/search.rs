@@ -0,0 +1,224 @@
+use crate::fastsearch::FastCandidate;
+use crate::integrity;
+use crate::matcher::Matcher;
+use crate::metrics::Metrics;
+use anyhow::Result;
+use rand::RngCore;
+use solana_sdk::signer::{keypair::Keypair, Signer};
+use std::sync::atomic::{AtomicBool, AtomicU64, Ordering};
+use std::sync::Arc;
+use std::time::Instant;
+use tokio::sync::mpsc;
+use tokio::time::Duration;
+
+/// How many attempts a worker accumulates locally before flushing into the
+/// shared Prometheus counter, to keep the hot loop contention-free.
+const METRICS_FLUSH_INTERVAL: u64 = 1000;
+
+/// One matching address's exported fields, independent of which search mode
+/// (slow `Keypair::new()` or fast point-increment) produced it.
+pub struct FoundAddress {
+    pub pub_key: String,
+    pub private_key_b58: String,
+    pub label: String,
+}
+
+/// Spawns one worker per core, each generating candidates until one
+/// satisfies `matcher`, `cancelled` is set, or another worker already found
+/// a match. Shared by the CLI generator and the HTTP job queue so both get
+/// the same `--fast` mode and metrics instrumentation instead of carrying
+/// two copies of the hot loop.
+pub async fn find_one(
+    matcher: Arc<dyn Matcher>,
+    attempts: Arc<AtomicU64>,
+    metrics: Option<Arc<Metrics>>,
+    num_cores: usize,
+    fast: bool,
+    cancelled: Arc<AtomicBool>,
+) -> Option<FoundAddress> {
+    let (tx, mut rx) = mpsc::channel::<FoundAddress>(1);
+    let found = Arc::new(AtomicBool::new(false));
+
+    if let Some(metrics) = &metrics {
+        metrics.set_active_workers(num_cores as i64);
+    }
+
+    let mut handles = Vec::new();
+    for thread_id in 0..num_cores {
+        let tx = tx.clone();
+        let found = found.clone();
+        let cancelled = cancelled.clone();
+        let attempts = attempts.clone();
+        let matcher = matcher.clone();
+        let metrics = metrics.clone();
+
+        let handle = tokio::task::spawn_blocking(move || {
+            if fast {
+                run_fast_worker(thread_id, matcher, attempts, metrics, found, cancelled, tx);
+            } else {
+                run_slow_worker(thread_id, num_cores, matcher, attempts, metrics, found, cancelled, tx);
+            }
+        });
+        handles.push(handle);
+    }
+
+    drop(tx);
+    let result = rx.recv().await;
+
+    found.store(true, Ordering::Relaxed);
+    for handle in handles {
+        let _ = handle.await;
+    }
+
+    if let Some(metrics) = &metrics {
+        metrics.set_active_workers(0);
+    }
+
+    result
+}
+
+fn run_slow_worker(
+    thread_id: usize,
+    num_cores: usize,
+    matcher: Arc<dyn Matcher>,
+    attempts: Arc<AtomicU64>,
+    metrics: Option<Arc<Metrics>>,
+    found: Arc<AtomicBool>,
+    cancelled: Arc<AtomicBool>,
+    tx: mpsc::Sender<FoundAddress>,
+) {
+    let mut local_attempts = 0u64;
+    let mut unflushed_attempts = 0u64;
+    let mut last_report = Instant::now();
+    let mut last_reported_attempts = 0u64;
+
+    loop {
+        if found.load(Ordering::Relaxed) || cancelled.load(Ordering::Relaxed) {
+            break;
+        }
+
+        let keypair = Keypair::new();
+        let address = keypair.pubkey().to_string();
+
+        local_attempts += 1;
+        unflushed_attempts += 1;
+        attempts.fetch_add(1, Ordering::Relaxed);
+
+        // Batch the Prometheus counter flush so the hot loop isn't paying
+        // atomic-contention cost on every iteration.
+        if unflushed_attempts >= METRICS_FLUSH_INTERVAL {
+            if let Some(metrics) = &metrics {
+                metrics.record_attempts(unflushed_attempts);
+            }
+            unflushed_attempts = 0;
+        }
+
+        // Report progress from thread 0 only every 5 seconds
+        if thread_id == 0 && last_report.elapsed() >= Duration::from_secs(5) {
+            let total_attempts = attempts.load(Ordering::Relaxed);
+            println!("🔄 Total attempts: {} (searching on {} cores)", total_attempts, num_cores);
+            if let Some(metrics) = &metrics {
+                // Rate over the interval since the last tick, not the
+                // all-time cumulative count — dividing `total_attempts` by
+                // the tick interval would make the reported rate grow
+                // roughly linearly with how long the search has run.
+                let elapsed = last_report.elapsed().as_secs_f64().max(f64::EPSILON);
+                metrics.set_attempts_per_sec((total_attempts - last_reported_attempts) as f64 / elapsed);
+            }
+            last_reported_attempts = total_attempts;
+            last_report = Instant::now();
+        }
+
+        if let Some(label) = matcher.matches(&address) {
+            found.store(true, Ordering::Relaxed);
+
+            println!("🎉 Found matching address after {} local attempts on thread {}!", local_attempts, thread_id);
+
+            if let Some(metrics) = &metrics {
+                if unflushed_attempts > 0 {
+                    metrics.record_attempts(unflushed_attempts);
+                }
+                metrics.record_match(label);
+            }
+
+            let found_address = FoundAddress {
+                pub_key: address,
+                private_key_b58: integrity::encode_slow_export(&keypair.to_bytes()),
+                label: label.to_string(),
+            };
+            let _ = tx.blocking_send(found_address);
+            break;
+        }
+    }
+}
+
+/// Same shape as `run_slow_worker`, but each worker samples one random
+/// secret scalar/nonce/public point up front and walks forward by point
+/// addition instead of drawing a fresh keypair (and paying a full scalar
+/// multiplication) on every attempt.
+fn run_fast_worker(
+    thread_id: usize,
+    matcher: Arc<dyn Matcher>,
+    attempts: Arc<AtomicU64>,
+    metrics: Option<Arc<Metrics>>,
+    found: Arc<AtomicBool>,
+    cancelled: Arc<AtomicBool>,
+    tx: mpsc::Sender<FoundAddress>,
+) {
+    let mut seed = [0u8; 32];
+    rand::rngs::OsRng.fill_bytes(&mut seed);
+    let mut nonce_prefix = [0u8; 32];
+    rand::rngs::OsRng.fill_bytes(&mut nonce_prefix);
+    let mut candidate = FastCandidate::sample(seed, nonce_prefix);
+    let mut unflushed_attempts = 0u64;
+    let mut last_report = Instant::now();
+    let mut last_reported_attempts = 0u64;
+
+    loop {
+        if found.load(Ordering::Relaxed) || cancelled.load(Ordering::Relaxed) {
+            break;
+        }
+
+        let address = candidate.address();
+        attempts.fetch_add(1, Ordering::Relaxed);
+        unflushed_attempts += 1;
+
+        if unflushed_attempts >= METRICS_FLUSH_INTERVAL {
+            if let Some(metrics) = &metrics {
+                metrics.record_attempts(unflushed_attempts);
+            }
+            unflushed_attempts = 0;
+        }
+
+        if thread_id == 0 && last_report.elapsed() >= Duration::from_secs(5) {
+            let total_attempts = attempts.load(Ordering::Relaxed);
+            if let Some(metrics) = &metrics {
+                let elapsed = last_report.elapsed().as_secs_f64().max(f64::EPSILON);
+                metrics.set_attempts_per_sec((total_attempts - last_reported_attempts) as f64 / elapsed);
+            }
+            last_reported_attempts = total_attempts;
+            last_report = Instant::now();
+        }
+
+        if let Some(label) = matcher.matches(&address) {
+            found.store(true, Ordering::Relaxed);
+
+            if let Some(metrics) = &metrics {
+                if unflushed_attempts > 0 {
+                    metrics.record_attempts(unflushed_attempts);
+                }
+                metrics.record_match(label);
+            }
+
+            let found_address = FoundAddress {
+                pub_key: address,
+                private_key_b58: integrity::encode_fast_export(&candidate.to_bytes()),
+                label: label.to_string(),
+            };
+            let _ = tx.blocking_send(found_address);
+            break;
+        }
+
+        candidate.advance();
+    }
+}
@@ -0,0 +1,276 @@
+use crate::matcher::{Matcher, MultiMatcher};
+use crate::metrics::Metrics;
+use crate::search;
+use crate::store::{AddressInsert, Store, StoreBackend};
+use anyhow::Result;
+use axum::{
+    extract::{Path, State},
+    http::StatusCode,
+    routing::{get, post},
+    Json, Router,
+};
+use serde::{Deserialize, Serialize};
+use std::collections::HashMap;
+use std::net::SocketAddr;
+use std::sync::atomic::{AtomicBool, AtomicU64, Ordering};
+use std::sync::Arc;
+use std::thread;
+use std::time::Instant;
+use tokio::sync::{Mutex, Semaphore};
+
+/// A keypair a running job has found so far.
+#[derive(Serialize, Clone)]
+struct FoundAddress {
+    pub_key: String,
+    private_key: String,
+    suffix_type: String,
+}
+
+#[derive(Serialize, Clone, Copy, PartialEq, Eq)]
+#[serde(rename_all = "snake_case")]
+enum JobState {
+    Running,
+    Completed,
+    Cancelled,
+}
+
+/// A single submitted vanity search, tracked independently of any other
+/// job running at the same time.
+struct Job {
+    pattern: String,
+    count: u32,
+    batch_size: u32,
+    attempts: Arc<AtomicU64>,
+    matches_found: Arc<AtomicU64>,
+    cancelled: Arc<AtomicBool>,
+    started_at: Instant,
+    state: Mutex<JobState>,
+    results: Mutex<Vec<FoundAddress>>,
+}
+
+/// Shared server state: the job table, a counter for allocating job ids,
+/// a bounded pool limiting how many jobs search concurrently, the storage
+/// backend jobs persist their finds to, and the same search-mode/metrics
+/// configuration the CLI runs with, so jobs submitted over HTTP get the
+/// same `--fast` mode and `/metrics` instrumentation as `AddressGenerator`.
+#[derive(Clone)]
+pub struct AppState {
+    jobs: Arc<Mutex<HashMap<String, Arc<Job>>>>,
+    next_job_id: Arc<AtomicU64>,
+    worker_slots: Arc<Semaphore>,
+    store: Arc<StoreBackend>,
+    fast: bool,
+    metrics: Option<Arc<Metrics>>,
+}
+
+impl AppState {
+    pub fn new(store: StoreBackend, max_concurrent_jobs: usize, fast: bool, metrics: Option<Arc<Metrics>>) -> Self {
+        Self {
+            jobs: Arc::new(Mutex::new(HashMap::new())),
+            next_job_id: Arc::new(AtomicU64::new(1)),
+            worker_slots: Arc::new(Semaphore::new(max_concurrent_jobs)),
+            store: Arc::new(store),
+            fast,
+            metrics,
+        }
+    }
+}
+
+#[derive(Deserialize)]
+struct SubmitJobRequest {
+    /// Suffix the generated address must end with.
+    pattern: String,
+    #[serde(default = "default_count")]
+    count: u32,
+    #[serde(default)]
+    batch_size: u32,
+}
+
+fn default_count() -> u32 {
+    1
+}
+
+#[derive(Serialize)]
+struct SubmitJobResponse {
+    job_id: String,
+}
+
+#[derive(Serialize)]
+struct JobStatusResponse {
+    job_id: String,
+    status: JobState,
+    pattern: String,
+    attempts: u64,
+    matches: u64,
+    attempts_per_sec: f64,
+}
+
+#[derive(Serialize)]
+struct JobResultsResponse {
+    job_id: String,
+    results: Vec<FoundAddress>,
+}
+
+async fn submit_job(
+    State(state): State<AppState>,
+    Json(req): Json<SubmitJobRequest>,
+) -> Result<Json<SubmitJobResponse>, (StatusCode, String)> {
+    let matcher = MultiMatcher::single_suffix(&req.pattern)
+        .map_err(|e| (StatusCode::BAD_REQUEST, e.to_string()))?;
+    let matcher: Arc<dyn Matcher> = Arc::new(matcher);
+
+    let job_id = state.next_job_id.fetch_add(1, Ordering::Relaxed).to_string();
+    let job = Arc::new(Job {
+        pattern: req.pattern.clone(),
+        count: req.count,
+        batch_size: req.batch_size,
+        attempts: Arc::new(AtomicU64::new(0)),
+        matches_found: Arc::new(AtomicU64::new(0)),
+        cancelled: Arc::new(AtomicBool::new(false)),
+        started_at: Instant::now(),
+        state: Mutex::new(JobState::Running),
+        results: Mutex::new(Vec::new()),
+    });
+
+    state.jobs.lock().await.insert(job_id.clone(), job.clone());
+    tokio::spawn(run_job(state.clone(), job, matcher));
+
+    Ok(Json(SubmitJobResponse { job_id }))
+}
+
+/// Drives one job to completion: acquires a slot in the bounded worker
+/// pool, then repeatedly searches for a matching address — via the same
+/// shared `search::find_one` worker loop the CLI's `AddressGenerator` uses,
+/// so HTTP-submitted jobs get the same `--fast` mode and metrics
+/// instrumentation — until `count` matches are found or the job is
+/// cancelled. Matches are buffered and flushed to the store in batches of
+/// `job.batch_size` (0 = upload all at the end), mirroring the CLI's
+/// batch-upload strategy instead of persisting one row at a time.
+async fn run_job(state: AppState, job: Arc<Job>, matcher: Arc<dyn Matcher>) {
+    let _permit = state.worker_slots.acquire().await;
+    let num_cores = thread::available_parallelism().map(|p| p.get()).unwrap_or(1);
+    let mut batch_records = Vec::new();
+
+    for _ in 0..job.count {
+        if job.cancelled.load(Ordering::Relaxed) {
+            break;
+        }
+
+        match search::find_one(
+            matcher.clone(),
+            job.attempts.clone(),
+            state.metrics.clone(),
+            num_cores,
+            state.fast,
+            job.cancelled.clone(),
+        )
+        .await
+        {
+            Some(found) => {
+                job.matches_found.fetch_add(1, Ordering::Relaxed);
+
+                batch_records.push(AddressInsert {
+                    pub_key: found.pub_key.clone(),
+                    private_key: found.private_key_b58.clone(),
+                    suffix_type: found.label.clone(),
+                });
+
+                if job.batch_size > 0 && batch_records.len() >= job.batch_size as usize {
+                    if let Err(e) = state.store.insert_batch(batch_records.clone()).await {
+                        println!("⚠️  Job {} failed to save batch: {}", job.pattern, e);
+                    }
+                    batch_records.clear();
+                }
+
+                job.results.lock().await.push(FoundAddress {
+                    pub_key: found.pub_key,
+                    private_key: found.private_key_b58,
+                    suffix_type: found.label,
+                });
+            }
+            None => break, // cancelled mid-search
+        }
+    }
+
+    if !batch_records.is_empty() {
+        if let Err(e) = state.store.insert_batch(batch_records).await {
+            println!("⚠️  Job {} failed to save final batch: {}", job.pattern, e);
+        }
+    }
+
+    let mut state_lock = job.state.lock().await;
+    *state_lock = if job.cancelled.load(Ordering::Relaxed) {
+        JobState::Cancelled
+    } else {
+        JobState::Completed
+    };
+}
+
+async fn get_job_status(
+    State(state): State<AppState>,
+    Path(job_id): Path<String>,
+) -> Result<Json<JobStatusResponse>, StatusCode> {
+    let jobs = state.jobs.lock().await;
+    let job = jobs.get(&job_id).ok_or(StatusCode::NOT_FOUND)?;
+
+    let attempts = job.attempts.load(Ordering::Relaxed);
+    let elapsed = job.started_at.elapsed().as_secs_f64().max(f64::EPSILON);
+
+    Ok(Json(JobStatusResponse {
+        job_id,
+        status: *job.state.lock().await,
+        pattern: job.pattern.clone(),
+        attempts,
+        matches: job.matches_found.load(Ordering::Relaxed),
+        attempts_per_sec: attempts as f64 / elapsed,
+    }))
+}
+
+async fn get_job_results(
+    State(state): State<AppState>,
+    Path(job_id): Path<String>,
+) -> Result<Json<JobResultsResponse>, StatusCode> {
+    let jobs = state.jobs.lock().await;
+    let job = jobs.get(&job_id).ok_or(StatusCode::NOT_FOUND)?;
+
+    Ok(Json(JobResultsResponse {
+        job_id,
+        results: job.results.lock().await.clone(),
+    }))
+}
+
+async fn cancel_job(
+    State(state): State<AppState>,
+    Path(job_id): Path<String>,
+) -> Result<StatusCode, StatusCode> {
+    let jobs = state.jobs.lock().await;
+    let job = jobs.get(&job_id).ok_or(StatusCode::NOT_FOUND)?;
+    job.cancelled.store(true, Ordering::Relaxed);
+    Ok(StatusCode::ACCEPTED)
+}
+
+fn router(state: AppState) -> Router {
+    Router::new()
+        .route("/jobs", post(submit_job))
+        .route("/jobs/:id", get(get_job_status))
+        .route("/jobs/:id/results", get(get_job_results))
+        .route("/jobs/:id/cancel", post(cancel_job))
+        .with_state(state)
+}
+
+/// Starts the daemon's HTTP API and blocks until it's shut down.
+pub async fn serve(
+    addr: SocketAddr,
+    store: StoreBackend,
+    max_concurrent_jobs: usize,
+    fast: bool,
+    metrics: Option<Arc<Metrics>>,
+) -> Result<()> {
+    let state = AppState::new(store, max_concurrent_jobs, fast, metrics);
+    let app = router(state);
+
+    println!("🛰️  Listening on http://{} (POST /jobs, GET /jobs/:id, GET /jobs/:id/results, POST /jobs/:id/cancel)", addr);
+    let listener = tokio::net::TcpListener::bind(addr).await?;
+    axum::serve(listener, app).await?;
+    Ok(())
+}
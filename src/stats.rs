@@ -0,0 +1,232 @@
+use std::fs::OpenOptions;
+use std::io::Write;
+use std::path::Path;
+use std::time::Duration;
+
+/// Log-scale histogram of "attempts needed before a match", bucketed at
+/// powers of two from 2^0 up to 2^32. Good enough resolution to tell a
+/// 4-character suffix apart from a 5-character one without needing exact
+/// counts.
+pub struct Histogram {
+    buckets: [u64; 33],
+}
+
+impl Histogram {
+    pub fn new() -> Self {
+        Self { buckets: [0; 33] }
+    }
+
+    /// Records that a search took `attempts` tries to find a match.
+    pub fn record(&mut self, attempts: u64) {
+        let bucket = 64 - attempts.max(1).leading_zeros() as usize - 1;
+        let bucket = bucket.min(self.buckets.len() - 1);
+        self.buckets[bucket] += 1;
+    }
+
+    fn total(&self) -> u64 {
+        self.buckets.iter().sum()
+    }
+
+    /// Returns the attempts-to-match value at the given percentile
+    /// (0.0..=1.0), taken as the upper edge of whichever bucket it falls in.
+    fn percentile(&self, pct: f64) -> u64 {
+        let total = self.total();
+        if total == 0 {
+            return 0;
+        }
+        let target = (total as f64 * pct).ceil() as u64;
+        let mut cumulative = 0u64;
+        for (i, &count) in self.buckets.iter().enumerate() {
+            cumulative += count;
+            if cumulative >= target {
+                return 1u64 << (i + 1);
+            }
+        }
+        1u64 << self.buckets.len()
+    }
+
+    pub fn median(&self) -> u64 {
+        self.percentile(0.5)
+    }
+
+    pub fn tail_p99(&self) -> u64 {
+        self.percentile(0.99)
+    }
+}
+
+/// A window of attempts/sec samples, taken on a fixed tick, from which
+/// throughput percentiles are computed.
+pub struct ThroughputSeries {
+    samples: Vec<f64>,
+}
+
+impl ThroughputSeries {
+    pub fn new() -> Self {
+        Self { samples: Vec::new() }
+    }
+
+    pub fn push(&mut self, attempts_per_sec: f64) {
+        self.samples.push(attempts_per_sec);
+    }
+
+    fn percentile(&self, pct: f64) -> f64 {
+        if self.samples.is_empty() {
+            return 0.0;
+        }
+        let mut sorted = self.samples.clone();
+        sorted.sort_by(|a, b| a.partial_cmp(b).unwrap());
+        let idx = ((sorted.len() as f64 - 1.0) * pct).round() as usize;
+        sorted[idx]
+    }
+
+    pub fn min(&self) -> f64 {
+        self.samples.iter().cloned().fold(f64::INFINITY, f64::min)
+    }
+
+    pub fn mean(&self) -> f64 {
+        if self.samples.is_empty() {
+            return 0.0;
+        }
+        self.samples.iter().sum::<f64>() / self.samples.len() as f64
+    }
+
+    pub fn p50(&self) -> f64 {
+        self.percentile(0.50)
+    }
+
+    pub fn p90(&self) -> f64 {
+        self.percentile(0.90)
+    }
+
+    pub fn p99(&self) -> f64 {
+        self.percentile(0.99)
+    }
+}
+
+/// Results of a single benchmark window: how fast the machine generates
+/// keypairs, and how many it needs before a pattern of this length hits.
+pub struct Stats {
+    pub pattern: String,
+    pub pattern_len: usize,
+    pub total_attempts: u64,
+    pub elapsed: Duration,
+    pub throughput: ThroughputSeries,
+    pub histogram: Histogram,
+    pub matches: u64,
+}
+
+impl Stats {
+    pub fn mean_attempts_per_match(&self) -> f64 {
+        if self.matches == 0 {
+            return 0.0;
+        }
+        self.total_attempts as f64 / self.matches as f64
+    }
+
+    pub fn print_table(&self) {
+        println!("\n📊 Benchmark results for pattern '{}'", self.pattern);
+        println!("⏱️  Window: {:.2}s", self.elapsed.as_secs_f64());
+        println!("🎯 Total attempts: {}", self.total_attempts);
+        println!("✨ Matches found: {}", self.matches);
+        println!(
+            "⚡ Throughput (attempts/s): min={:.0} mean={:.0} p50={:.0} p90={:.0} p99={:.0}",
+            self.throughput.min(),
+            self.throughput.mean(),
+            self.throughput.p50(),
+            self.throughput.p90(),
+            self.throughput.p99()
+        );
+        println!(
+            "📈 Attempts-to-match: median={} p99={} mean={:.1}",
+            self.histogram.median(),
+            self.histogram.tail_p99(),
+            self.mean_attempts_per_match()
+        );
+    }
+
+    fn csv_row(&self) -> String {
+        format!(
+            "{},{},{},{:.3},{:.0},{:.0},{:.0},{},{:.1}\n",
+            self.pattern,
+            self.pattern_len,
+            self.total_attempts,
+            self.elapsed.as_secs_f64(),
+            self.throughput.p50(),
+            self.throughput.p90(),
+            self.throughput.p99(),
+            self.matches,
+            self.mean_attempts_per_match()
+        )
+    }
+
+    /// Appends this run as one CSV row to `path`, writing the header first
+    /// if the file doesn't exist yet.
+    pub fn append_csv(&self, path: &Path) -> std::io::Result<()> {
+        let write_header = !path.exists();
+        let mut file = OpenOptions::new().create(true).append(true).open(path)?;
+        if write_header {
+            writeln!(
+                file,
+                "pattern,pattern_len,total_attempts,elapsed_s,attempts_per_s_p50,attempts_per_s_p90,attempts_per_s_p99,matches,mean_attempts_per_match"
+            )?;
+        }
+        file.write_all(self.csv_row().as_bytes())
+    }
+}
+
+/// A benchmark that hammers some operation for a fixed wall-clock window
+/// instead of running to completion, then reports a `Stats` distribution.
+pub trait Benchmark {
+    /// One-time setup before the timed window starts (e.g. picking a
+    /// random pattern of the requested length).
+    fn prepare(&mut self);
+
+    /// Runs for `window` and returns the collected stats.
+    fn run(&mut self, window: Duration) -> Stats;
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn histogram_buckets_by_power_of_two() {
+        let mut hist = Histogram::new();
+        for _ in 0..9 {
+            hist.record(100); // falls in the 64..128 bucket
+        }
+        hist.record(100_000); // a single high outlier, well into the tail
+
+        assert_eq!(hist.median(), 128);
+        assert_eq!(hist.tail_p99(), 131072);
+    }
+
+    #[test]
+    fn histogram_with_no_samples_reports_zero() {
+        let hist = Histogram::new();
+        assert_eq!(hist.median(), 0);
+        assert_eq!(hist.tail_p99(), 0);
+    }
+
+    #[test]
+    fn throughput_series_percentiles_and_aggregates() {
+        let mut series = ThroughputSeries::new();
+        for sample in [10.0, 20.0, 30.0, 40.0, 50.0] {
+            series.push(sample);
+        }
+
+        assert_eq!(series.min(), 10.0);
+        assert_eq!(series.mean(), 30.0);
+        assert_eq!(series.p50(), 30.0);
+        assert_eq!(series.p90(), 50.0);
+        assert_eq!(series.p99(), 50.0);
+    }
+
+    #[test]
+    fn empty_throughput_series_reports_zero_mean_and_infinite_min() {
+        let series = ThroughputSeries::new();
+        assert_eq!(series.min(), f64::INFINITY);
+        assert_eq!(series.mean(), 0.0);
+        assert_eq!(series.p50(), 0.0);
+    }
+}
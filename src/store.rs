@@ -0,0 +1,252 @@
+use anyhow::Result;
+use async_trait::async_trait;
+use bb8::Pool;
+use bb8_postgres::PostgresConnectionManager;
+use chrono::Utc;
+use futures_util::pin_mut;
+use reqwest::Client;
+use serde::Serialize;
+use std::env;
+use tokio_postgres::binary_copy::BinaryCopyInWriter;
+use tokio_postgres::types::Type;
+use tokio_postgres::NoTls;
+
+/// A found address ready to be persisted, independent of which backend
+/// ends up storing it.
+#[derive(Serialize, Clone)]
+pub struct AddressInsert {
+    pub pub_key: String,
+    pub private_key: String, // Base58 of a one-byte mode tag plus the 64 key bytes (see `integrity::encode_slow_export`); not loadable directly as a Solana `Keypair`
+    pub suffix_type: String, // Label of whichever pattern matched, e.g. "suffix:pump"
+}
+
+/// Where found addresses get persisted. Both the Supabase REST path and
+/// the pooled Postgres path implement this so `AddressGenerator` doesn't
+/// care which one it's talking to.
+#[async_trait]
+pub trait Store: Send + Sync {
+    async fn insert_one(&self, record: AddressInsert) -> Result<()>;
+    async fn insert_batch(&self, records: Vec<AddressInsert>) -> Result<()>;
+}
+
+pub struct SupabaseClient {
+    client: Client,
+    url: String,
+    key: String,
+}
+
+impl SupabaseClient {
+    pub fn new() -> Result<Self> {
+        let url = env::var("SUPABASE_URL")?;
+        let key = env::var("SUPABASE_ANON_KEY")?;
+
+        Ok(Self {
+            client: Client::new(),
+            url,
+            key,
+        })
+    }
+}
+
+/// Builds the `mint_addresses` REST endpoint URL from a Supabase project
+/// URL, e.g. `https://xyz.supabase.co` -> `https://xyz.supabase.co/rest/v1/mint_addresses`.
+/// Pulled out of `insert_one`/`insert_batch` so it can be unit-tested
+/// without a real Supabase project to talk to.
+fn mint_addresses_url(base: &str) -> String {
+    format!("{}/rest/v1/mint_addresses", base)
+}
+
+#[async_trait]
+impl Store for SupabaseClient {
+    async fn insert_one(&self, record: AddressInsert) -> Result<()> {
+        let response = self
+            .client
+            .post(&mint_addresses_url(&self.url))
+            .header("apikey", &self.key)
+            .header("Authorization", format!("Bearer {}", self.key))
+            .header("Content-Type", "application/json")
+            .json(&record)
+            .send()
+            .await?;
+
+        if response.status().is_success() {
+            println!("✅ Successfully saved address {} to Supabase", record.pub_key);
+        } else {
+            let error_text = response.text().await?;
+            println!("❌ Failed to save address: {}", error_text);
+        }
+
+        Ok(())
+    }
+
+    async fn insert_batch(&self, records: Vec<AddressInsert>) -> Result<()> {
+        if records.is_empty() {
+            return Ok(());
+        }
+
+        let response = self
+            .client
+            .post(&mint_addresses_url(&self.url))
+            .header("apikey", &self.key)
+            .header("Authorization", format!("Bearer {}", self.key))
+            .header("Content-Type", "application/json")
+            .json(&records)
+            .send()
+            .await?;
+
+        if response.status().is_success() {
+            println!("✅ Successfully saved {} addresses to Supabase in batch", records.len());
+        } else {
+            let error_text = response.text().await?;
+            println!("❌ Failed to save batch: {}", error_text);
+
+            // Fallback: try individual inserts
+            println!("🔄 Retrying with individual inserts...");
+            for record in records {
+                if let Err(e) = self.insert_one(record).await {
+                    println!("⚠️  Individual insert failed: {}", e);
+                }
+            }
+        }
+
+        Ok(())
+    }
+}
+
+/// Pooled Postgres backend. Batches are written with a binary `COPY ...
+/// FROM STDIN`, which is dramatically cheaper than one `INSERT` per row
+/// once many generator threads are persisting concurrently.
+pub struct PgStore {
+    pool: Pool<PostgresConnectionManager<NoTls>>,
+}
+
+impl PgStore {
+    pub async fn new(database_url: &str) -> Result<Self> {
+        let manager = PostgresConnectionManager::new_from_stringlike(database_url, NoTls)?;
+        let pool = Pool::builder().max_size(16).build(manager).await?;
+        Ok(Self { pool })
+    }
+
+    async fn copy_insert(&self, records: &[AddressInsert]) -> Result<()> {
+        let conn = self.pool.get().await?;
+        let sink = conn
+            .copy_in("COPY mint_addresses (pub_key, private_key, suffix_type, created_at) FROM STDIN BINARY")
+            .await?;
+        let types = [Type::TEXT, Type::TEXT, Type::TEXT, Type::TIMESTAMPTZ];
+        let writer = BinaryCopyInWriter::new(sink, &types);
+        pin_mut!(writer);
+
+        let now = Utc::now();
+        for record in records {
+            writer
+                .as_mut()
+                .write(&[&record.pub_key, &record.private_key, &record.suffix_type, &now])
+                .await?;
+        }
+        writer.finish().await?;
+        Ok(())
+    }
+}
+
+#[async_trait]
+impl Store for PgStore {
+    async fn insert_one(&self, record: AddressInsert) -> Result<()> {
+        let conn = self.pool.get().await?;
+        conn.execute(
+            "INSERT INTO mint_addresses (pub_key, private_key, suffix_type, created_at) VALUES ($1, $2, $3, now())",
+            &[&record.pub_key, &record.private_key, &record.suffix_type],
+        )
+        .await?;
+        println!("✅ Successfully saved address {} to Postgres", record.pub_key);
+        Ok(())
+    }
+
+    async fn insert_batch(&self, records: Vec<AddressInsert>) -> Result<()> {
+        if records.is_empty() {
+            return Ok(());
+        }
+
+        let count = records.len();
+        match self.copy_insert(&records).await {
+            Ok(()) => {
+                println!("✅ COPY-inserted {} addresses into Postgres", count);
+                Ok(())
+            }
+            Err(e) => {
+                println!("⚠️  COPY insert failed ({}), falling back to row-by-row inserts", e);
+                for record in records {
+                    if let Err(e) = self.insert_one(record).await {
+                        println!("⚠️  Individual insert failed: {}", e);
+                    }
+                }
+                Ok(())
+            }
+        }
+    }
+}
+
+/// The concrete backend in use for this run, chosen once at startup from
+/// env vars. An enum (rather than a bare `dyn Store`) keeps construction
+/// in one place and matches how `Commands` already picks between modes.
+pub enum StoreBackend {
+    Supabase(SupabaseClient),
+    Postgres(PgStore),
+}
+
+impl StoreBackend {
+    /// Prefers `DATABASE_URL` (the pooled Postgres backend); falls back to
+    /// the existing Supabase REST path when only `SUPABASE_URL` is set.
+    pub async fn from_env() -> Result<Self> {
+        if let Ok(database_url) = env::var("DATABASE_URL") {
+            println!("🐘 Using pooled Postgres backend");
+            return Ok(StoreBackend::Postgres(PgStore::new(&database_url).await?));
+        }
+
+        println!("🗄️  Using Supabase backend");
+        let supabase = SupabaseClient::new().map_err(|e| {
+            anyhow::anyhow!(
+                "Failed to initialize a storage backend: {}. Set DATABASE_URL or SUPABASE_URL/SUPABASE_ANON_KEY.",
+                e
+            )
+        })?;
+        Ok(StoreBackend::Supabase(supabase))
+    }
+}
+
+#[async_trait]
+impl Store for StoreBackend {
+    async fn insert_one(&self, record: AddressInsert) -> Result<()> {
+        match self {
+            StoreBackend::Supabase(s) => s.insert_one(record).await,
+            StoreBackend::Postgres(s) => s.insert_one(record).await,
+        }
+    }
+
+    async fn insert_batch(&self, records: Vec<AddressInsert>) -> Result<()> {
+        match self {
+            StoreBackend::Supabase(s) => s.insert_batch(records).await,
+            StoreBackend::Postgres(s) => s.insert_batch(records).await,
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn mint_addresses_url_appends_the_rest_path() {
+        assert_eq!(
+            mint_addresses_url("https://xyz.supabase.co"),
+            "https://xyz.supabase.co/rest/v1/mint_addresses"
+        );
+    }
+
+    #[test]
+    fn mint_addresses_url_does_not_collapse_a_trailing_slash() {
+        assert_eq!(
+            mint_addresses_url("https://xyz.supabase.co/"),
+            "https://xyz.supabase.co//rest/v1/mint_addresses"
+        );
+    }
+}